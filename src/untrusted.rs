@@ -103,6 +103,15 @@
 )]
 #![no_std]
 
+#[cfg(test)]
+extern crate std;
+
+use core::fmt;
+
+/// The default number of bytes shown per line by `InputDisplay` and
+/// `ReaderDisplay`.
+const DEFAULT_DISPLAY_WIDTH: usize = 16;
+
 /// A wrapper around `&'a [u8]` that helps in writing panic-free code.
 ///
 /// No methods of `Input` will ever panic.
@@ -175,6 +184,37 @@ impl<'a> Input<'a> {
     /// are not written using the Input/Reader framework.
     #[inline]
     pub fn as_slice_less_safe(&self) -> &'a [u8] { self.0 }
+
+    /// Returns a builder for a diagnostic, hex+ASCII rendering of this
+    /// `Input`'s bytes, suitable for use in error messages.
+    ///
+    /// This does not expose the bytes in a way that `as_slice_less_safe`
+    /// does; it only renders them through `fmt::Display`.
+    #[inline]
+    pub fn display(&self) -> InputDisplay<'a> {
+        InputDisplay { input: *self, width: DEFAULT_DISPLAY_WIDTH }
+    }
+
+    /// Returns the number of times `needle` occurs in the input.
+    #[inline]
+    pub fn count(&self, needle: u8) -> usize { self.0.iter().filter(|&&b| b == needle).count() }
+
+    /// Returns `true` if this `Input`'s bytes lie entirely within the memory
+    /// range of `parent`'s bytes, and `false` otherwise.
+    ///
+    /// This is useful for asserting that a sub-`Input` produced by parsing
+    /// really did come from the `Input` it was supposedly parsed from. It
+    /// compares the start and end pointers of the two underlying slices; it
+    /// does not compare byte contents, so it says nothing about whether the
+    /// bytes match.
+    #[inline]
+    pub fn is_within(&self, parent: &Input<'_>) -> bool {
+        let self_start = self.0.as_ptr() as usize;
+        let self_end = self_start + self.0.len();
+        let parent_start = parent.0.as_ptr() as usize;
+        let parent_end = parent_start + parent.0.len();
+        self_start >= parent_start && self_end <= parent_end
+    }
 }
 
 // #[derive(PartialEq)] would result in lifetime bounds that are
@@ -222,13 +262,13 @@ where
 /// byte of the input is accidentally left unprocessed. The methods of `Reader`
 /// never panic, so `Reader` also assists the writing of panic-free code.
 #[derive(Debug)]
-pub struct Reader<'a>(Input<'a>);
+pub struct Reader<'a>(Input<'a>, Input<'a>);
 
 impl<'a> Reader<'a> {
     /// Construct a new Reader for the given input. Use `read_all` or
     /// `read_all_optional` instead of `Reader::new` whenever possible.
     #[inline]
-    pub fn new(input: Input<'a>) -> Self { Self(input) }
+    pub fn new(input: Input<'a>) -> Self { Self(input, input) }
 
     /// Returns `true` if the reader is at the end of the input, and `false`
     /// otherwise.
@@ -240,6 +280,11 @@ impl<'a> Reader<'a> {
     #[inline]
     pub fn peek(&self, b: u8) -> bool { self.0.first().map(|b| *b) == Some(b) }
 
+    /// Returns the next input byte without consuming it, or `None` if the
+    /// `Reader` is at the end of the input.
+    #[inline]
+    pub fn peek_byte(&self) -> Option<u8> { self.0.first().copied() }
+
     /// Reads the next input byte.
     ///
     /// Returns `Ok(b)` where `b` is the next input byte, or `Err(EndOfInput)`
@@ -270,6 +315,114 @@ impl<'a> Reader<'a> {
         core::mem::replace(&mut self.0, Input::empty())
     }
 
+    /// Reads the next input byte if it is present and `pred` returns `true`
+    /// for it, consuming the byte. Returns `None`, without consuming
+    /// anything, if the `Reader` is at the end of the input or `pred`
+    /// returns `false`.
+    #[inline]
+    pub fn read_byte_if<F>(&mut self, pred: F) -> Option<u8>
+    where
+        F: FnOnce(u8) -> bool,
+    {
+        match self.0.first() {
+            Some(&b) if pred(b) => {
+                let _ = self.read_byte();
+                Some(b)
+            },
+            _ => None,
+        }
+    }
+
+    /// Consumes the longest run of input bytes, starting from the current
+    /// position, for which `pred` returns `true`, and returns the consumed
+    /// bytes as an `Input`.
+    #[inline]
+    pub fn skip_while<F>(&mut self, mut pred: F) -> Input<'a>
+    where
+        F: FnMut(u8) -> bool,
+    {
+        let (bytes, ()) = self
+            .read_partial(|input| {
+                while let Some(&b) = input.0.first() {
+                    if !pred(b) {
+                        break;
+                    }
+                    let _ = input.read_byte();
+                }
+                Ok::<_, core::convert::Infallible>(())
+            })
+            .unwrap();
+        bytes
+    }
+
+    /// Reads a big-endian `u16` (2 bytes).
+    #[inline]
+    pub fn read_u16be(&mut self) -> Result<u16, EndOfInput> {
+        let hi = self.read_byte()?;
+        let lo = self.read_byte()?;
+        Ok(u16::from(hi) << 8 | u16::from(lo))
+    }
+
+    /// Reads a big-endian 24-bit unsigned integer (3 bytes), returned as a
+    /// `u32`.
+    #[inline]
+    pub fn read_u24be(&mut self) -> Result<u32, EndOfInput> {
+        let hi = self.read_byte()?;
+        let mid = self.read_byte()?;
+        let lo = self.read_byte()?;
+        Ok(u32::from(hi) << 16 | u32::from(mid) << 8 | u32::from(lo))
+    }
+
+    /// Reads a big-endian `u32` (4 bytes).
+    #[inline]
+    pub fn read_u32be(&mut self) -> Result<u32, EndOfInput> {
+        let hi = self.read_u16be()?;
+        let lo = self.read_u16be()?;
+        Ok(u32::from(hi) << 16 | u32::from(lo))
+    }
+
+    /// Reads a big-endian `u64` (8 bytes).
+    #[inline]
+    pub fn read_u64be(&mut self) -> Result<u64, EndOfInput> {
+        let hi = self.read_u32be()?;
+        let lo = self.read_u32be()?;
+        Ok(u64::from(hi) << 32 | u64::from(lo))
+    }
+
+    /// Reads a little-endian `u16` (2 bytes).
+    #[inline]
+    pub fn read_u16le(&mut self) -> Result<u16, EndOfInput> {
+        let lo = self.read_byte()?;
+        let hi = self.read_byte()?;
+        Ok(u16::from(hi) << 8 | u16::from(lo))
+    }
+
+    /// Reads a little-endian 24-bit unsigned integer (3 bytes), returned as a
+    /// `u32`.
+    #[inline]
+    pub fn read_u24le(&mut self) -> Result<u32, EndOfInput> {
+        let lo = self.read_byte()?;
+        let mid = self.read_byte()?;
+        let hi = self.read_byte()?;
+        Ok(u32::from(hi) << 16 | u32::from(mid) << 8 | u32::from(lo))
+    }
+
+    /// Reads a little-endian `u32` (4 bytes).
+    #[inline]
+    pub fn read_u32le(&mut self) -> Result<u32, EndOfInput> {
+        let lo = self.read_u16le()?;
+        let hi = self.read_u16le()?;
+        Ok(u32::from(hi) << 16 | u32::from(lo))
+    }
+
+    /// Reads a little-endian `u64` (8 bytes).
+    #[inline]
+    pub fn read_u64le(&mut self) -> Result<u64, EndOfInput> {
+        let lo = self.read_u32le()?;
+        let hi = self.read_u32le()?;
+        Ok(u64::from(hi) << 32 | u64::from(lo))
+    }
+
     /// Calls `read()` with the given input as a `Reader`. On success, returns a
     /// pair `(bytes_read, r)` where `bytes_read` is what `read()` consumed and
     /// `r` is `read()`'s return value.
@@ -296,9 +449,434 @@ impl<'a> Reader<'a> {
     /// Skips the reader to the end of the input.
     #[inline]
     pub fn skip_to_end(&mut self) -> () { let _ = self.read_bytes_to_end(); }
+
+    /// Reads an ILInt-encoded variable-length unsigned integer.
+    ///
+    /// The encoding is one header byte `h`. If `h < 0xF8` the decoded value is
+    /// `h`. Otherwise `n = h - 0xF8 + 1` (`1..=8`) further bytes follow,
+    /// interpreted as a big-endian unsigned integer `v`, and the decoded value
+    /// is `v + 0xF8`.
+    ///
+    /// Returns `Err(IlIntError::Overflow)` if the decoded value would not fit
+    /// in a `u64`, and `Err(IlIntError::EndOfInput)` if the `Reader` runs out
+    /// of input before the encoding is complete.
+    pub fn read_ilint(&mut self) -> Result<u64, IlIntError> {
+        let h = self.read_byte().map_err(|_| IlIntError::EndOfInput)?;
+        if h < 0xF8 {
+            return Ok(u64::from(h));
+        }
+        let n = h - 0xF8 + 1;
+        let mut v: u64 = 0;
+        for _ in 0..n {
+            let b = self.read_byte().map_err(|_| IlIntError::EndOfInput)?;
+            v = (v << 8) | u64::from(b);
+        }
+        v.checked_add(0xF8).ok_or(IlIntError::Overflow)
+    }
+
+    /// Returns a builder for a diagnostic, hex+ASCII rendering of the
+    /// `Reader`'s underlying input, marking the boundary between the bytes
+    /// already consumed and the remaining input.
+    #[inline]
+    pub fn display(&self) -> ReaderDisplay<'a> {
+        let consumed = self.1.len() - self.0.len();
+        ReaderDisplay { original: self.1, consumed, width: DEFAULT_DISPLAY_WIDTH }
+    }
+
+    /// Returns a `Mark` for the `Reader`'s current position.
+    ///
+    /// Use `get_input_between` or `since` to later recover the `Input`
+    /// spanning from this position to another one.
+    #[inline]
+    pub fn mark(&self) -> Mark<'a> { Mark { original: self.1, offset: self.1.len() - self.0.len() } }
+
+    /// Returns the `Input` spanning from `start` to `end`, both of which must
+    /// have been returned by `self.mark()`.
+    ///
+    /// Returns `None` if `start` is after `end`, or if either was marked
+    /// against a different input than this `Reader`'s.
+    pub fn get_input_between(&self, start: Mark<'a>, end: Mark<'a>) -> Option<Input<'a>> {
+        if !self.1.is_within(&start.original) || !start.original.is_within(&self.1) {
+            return None;
+        }
+        if !self.1.is_within(&end.original) || !end.original.is_within(&self.1) {
+            return None;
+        }
+        if start.offset > end.offset {
+            return None;
+        }
+        let (_, after_start) = self.1.split_at(start.offset)?;
+        let (between, _) = after_start.split_at(end.offset - start.offset)?;
+        Some(between)
+    }
+
+    /// Returns the `Input` spanning from `start` to the `Reader`'s current
+    /// position.
+    ///
+    /// Returns `None` under the same conditions as `get_input_between`.
+    #[inline]
+    pub fn since(&self, start: Mark<'a>) -> Option<Input<'a>> { self.get_input_between(start, self.mark()) }
+}
+
+/// An opaque position within the input a `Reader` was constructed from,
+/// returned by `Reader::mark`.
+///
+/// A `Mark` remembers which `Input` it was taken from. Passing it to
+/// `Reader::get_input_between` or `since` on a `Reader` over a different
+/// `Input` returns `None` rather than reconstructing a span from the wrong
+/// backing bytes.
+#[derive(Clone, Copy, Debug)]
+pub struct Mark<'a> {
+    original: Input<'a>,
+    offset: usize,
+}
+
+/// A builder for a diagnostic, hex+ASCII rendering of an `Input`'s bytes.
+///
+/// Constructed by `Input::display()`. Implements `fmt::Display`; does not
+/// allocate.
+#[derive(Clone, Copy, Debug)]
+pub struct InputDisplay<'a> {
+    input: Input<'a>,
+    width: usize,
+}
+
+impl<'a> InputDisplay<'a> {
+    /// Sets the number of bytes shown per line. Defaults to 16.
+    #[inline]
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = core::cmp::max(width, 1);
+        self
+    }
+}
+
+impl<'a> fmt::Display for InputDisplay<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_hex_dump(f, self.input.as_slice_less_safe(), self.width, 0)
+    }
+}
+
+/// A builder for a diagnostic, hex+ASCII rendering of a `Reader`, marking the
+/// boundary between the bytes already consumed and the remaining input.
+///
+/// Constructed by `Reader::display()`. Implements `fmt::Display`; does not
+/// allocate.
+#[derive(Clone, Copy, Debug)]
+pub struct ReaderDisplay<'a> {
+    original: Input<'a>,
+    consumed: usize,
+    width: usize,
+}
+
+impl<'a> ReaderDisplay<'a> {
+    /// Sets the number of bytes shown per line. Defaults to 16.
+    #[inline]
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = core::cmp::max(width, 1);
+        self
+    }
+}
+
+impl<'a> fmt::Display for ReaderDisplay<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let bytes = self.original.as_slice_less_safe();
+        let (consumed, remaining) = bytes.split_at(self.consumed);
+        if !consumed.is_empty() {
+            writeln!(f, "consumed:")?;
+            write_hex_dump(f, consumed, self.width, 0)?;
+        }
+        writeln!(f, "remaining (cursor at offset {:#x}):", self.consumed)?;
+        write_hex_dump(f, remaining, self.width, self.consumed)
+    }
+}
+
+/// Writes `bytes` as offset-labeled lines of up to `width` hex bytes each,
+/// followed by their ASCII rendering (non-printable bytes shown as `.`).
+fn write_hex_dump(f: &mut fmt::Formatter, bytes: &[u8], width: usize, base_offset: usize) -> fmt::Result {
+    if bytes.is_empty() {
+        return writeln!(f, "{:08x}  (empty)", base_offset);
+    }
+    for (i, line) in bytes.chunks(width).enumerate() {
+        write!(f, "{:08x}  ", base_offset + i * width)?;
+        for b in line {
+            write!(f, "{:02x} ", b)?;
+        }
+        for _ in line.len()..width {
+            write!(f, "   ")?;
+        }
+        write!(f, " |")?;
+        for &b in line {
+            let c = if b.is_ascii_graphic() || b == b' ' { char::from(b) } else { '.' };
+            write!(f, "{}", c)?;
+        }
+        writeln!(f, "|")?;
+    }
+    Ok(())
 }
 
 /// The error type used to indicate the end of the input was reached before the
 /// operation could be completed.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct EndOfInput;
+
+/// The error type returned by `Reader::read_ilint`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IlIntError {
+    /// The end of the input was reached before the ILInt encoding was
+    /// complete.
+    EndOfInput,
+
+    /// The decoded value does not fit in a `u64`.
+    Overflow,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_u16be_reads_big_endian() {
+        let input = Input::from(&[0x01, 0x02]);
+        let value = input.read_all(EndOfInput, |r| r.read_u16be()).unwrap();
+        assert_eq!(value, 0x0102);
+    }
+
+    #[test]
+    fn read_u24be_reads_big_endian() {
+        let input = Input::from(&[0x01, 0x02, 0x03]);
+        let value = input.read_all(EndOfInput, |r| r.read_u24be()).unwrap();
+        assert_eq!(value, 0x0001_0203);
+    }
+
+    #[test]
+    fn read_u32be_reads_big_endian() {
+        let input = Input::from(&[0x01, 0x02, 0x03, 0x04]);
+        let value = input.read_all(EndOfInput, |r| r.read_u32be()).unwrap();
+        assert_eq!(value, 0x0102_0304);
+    }
+
+    #[test]
+    fn read_u64be_reads_big_endian() {
+        let input = Input::from(&[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+        let value = input.read_all(EndOfInput, |r| r.read_u64be()).unwrap();
+        assert_eq!(value, 0x0102_0304_0506_0708);
+    }
+
+    #[test]
+    fn read_uxxle_reads_little_endian() {
+        let input = Input::from(&[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+        let value = input.read_all(EndOfInput, |r| r.read_u64le()).unwrap();
+        assert_eq!(value, 0x0807_0605_0403_0201);
+
+        let input = Input::from(&[0x01, 0x02, 0x03, 0x04]);
+        let value = input.read_all(EndOfInput, |r| r.read_u32le()).unwrap();
+        assert_eq!(value, 0x0403_0201);
+
+        let input = Input::from(&[0x01, 0x02, 0x03]);
+        let value = input.read_all(EndOfInput, |r| r.read_u24le()).unwrap();
+        assert_eq!(value, 0x0003_0201);
+
+        let input = Input::from(&[0x01, 0x02]);
+        let value = input.read_all(EndOfInput, |r| r.read_u16le()).unwrap();
+        assert_eq!(value, 0x0201);
+    }
+
+    #[test]
+    fn read_u32be_on_truncated_input_is_end_of_input() {
+        let input = Input::from(&[0x01, 0x02]);
+        let result = input.read_all(EndOfInput, |r| r.read_u32be());
+        assert_eq!(result, Err(EndOfInput));
+    }
+
+    #[test]
+    fn read_ilint_single_byte_values_decode_as_is() {
+        let input = Input::from(&[0x00]);
+        let value = input.read_all(IlIntError::EndOfInput, |r| r.read_ilint()).unwrap();
+        assert_eq!(value, 0);
+
+        let input = Input::from(&[0xF7]);
+        let value = input.read_all(IlIntError::EndOfInput, |r| r.read_ilint()).unwrap();
+        assert_eq!(value, 0xF7);
+    }
+
+    #[test]
+    fn read_ilint_multi_byte_values_add_the_base() {
+        // h == 0xF8 means n == 1 trailing byte.
+        let input = Input::from(&[0xF8, 0x00]);
+        let value = input.read_all(IlIntError::EndOfInput, |r| r.read_ilint()).unwrap();
+        assert_eq!(value, 0xF8);
+
+        // h == 0xFF means n == 8 trailing bytes.
+        let input = Input::from(&[0xFF, 0, 0, 0, 0, 0, 0, 0, 1]);
+        let value = input.read_all(IlIntError::EndOfInput, |r| r.read_ilint()).unwrap();
+        assert_eq!(value, 1 + 0xF8);
+    }
+
+    #[test]
+    fn read_ilint_overflowing_value_is_an_error() {
+        // h == 0xFF, n == 8 trailing bytes of all-0xFF: v == u64::MAX, and
+        // v + 0xF8 overflows.
+        let input = Input::from(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+        let result = input.read_all(IlIntError::EndOfInput, |r| r.read_ilint());
+        assert_eq!(result, Err(IlIntError::Overflow));
+    }
+
+    #[test]
+    fn read_ilint_truncated_trailing_bytes_is_end_of_input() {
+        let input = Input::from(&[0xFF, 0, 0]);
+        let result = input.read_all(IlIntError::EndOfInput, |r| r.read_ilint());
+        assert_eq!(result, Err(IlIntError::EndOfInput));
+
+        let input = Input::from(&[0xF8]);
+        let result = input.read_all(IlIntError::EndOfInput, |r| r.read_ilint());
+        assert_eq!(result, Err(IlIntError::EndOfInput));
+    }
+
+    #[test]
+    fn peek_byte_returns_next_byte_without_consuming() {
+        let input = Input::from(&[0x01, 0x02]);
+        let mut r = Reader::new(input);
+        assert_eq!(r.peek_byte(), Some(0x01));
+        assert_eq!(r.peek_byte(), Some(0x01));
+        assert_eq!(r.read_byte(), Ok(0x01));
+        assert_eq!(r.peek_byte(), Some(0x02));
+    }
+
+    #[test]
+    fn peek_byte_at_end_of_input_is_none() {
+        let input = Input::from(&[]);
+        let r = Reader::new(input);
+        assert_eq!(r.peek_byte(), None);
+    }
+
+    #[test]
+    fn read_byte_if_consumes_only_on_match() {
+        let input = Input::from(&[b'a', b'b']);
+        let mut r = Reader::new(input);
+        assert_eq!(r.read_byte_if(|b| b == b'z'), None);
+        assert_eq!(r.read_byte_if(|b| b == b'a'), Some(b'a'));
+        assert_eq!(r.read_byte(), Ok(b'b'));
+    }
+
+    #[test]
+    fn read_byte_if_at_end_of_input_is_none() {
+        let input = Input::from(&[]);
+        let mut r = Reader::new(input);
+        assert_eq!(r.read_byte_if(|_| true), None);
+    }
+
+    #[test]
+    fn skip_while_consumes_the_longest_matching_run() {
+        let input = Input::from(b"   rest");
+        let mut r = Reader::new(input);
+        let skipped = r.skip_while(|b| b == b' ');
+        assert_eq!(skipped, &b"   "[..]);
+        assert_eq!(r.read_bytes_to_end(), &b"rest"[..]);
+    }
+
+    #[test]
+    fn skip_while_with_no_match_consumes_nothing() {
+        let input = Input::from(b"rest");
+        let mut r = Reader::new(input);
+        let skipped = r.skip_while(|b| b == b' ');
+        assert_eq!(skipped, &b""[..]);
+        assert_eq!(r.read_bytes_to_end(), &b"rest"[..]);
+    }
+
+    #[test]
+    fn skip_while_at_end_of_input_consumes_nothing() {
+        let input = Input::from(&[]);
+        let mut r = Reader::new(input);
+        let skipped = r.skip_while(|_| true);
+        assert_eq!(skipped, &b""[..]);
+        assert!(r.at_end());
+    }
+
+    #[test]
+    fn input_display_renders_offset_hex_and_ascii() {
+        let input = Input::from(b"hi");
+        let rendered = std::format!("{}", input.display());
+        assert!(rendered.contains("00000000"));
+        assert!(rendered.contains("68 69"));
+        assert!(rendered.contains("|hi|"));
+    }
+
+    #[test]
+    fn reader_display_marks_the_cursor_and_omits_empty_consumed_section() {
+        let input = Input::from(b"hi");
+        let r = Reader::new(input);
+        let rendered = std::format!("{}", r.display());
+        assert!(!rendered.contains("consumed:"));
+        assert!(rendered.contains("cursor at offset 0x0"));
+
+        let mut r = Reader::new(input);
+        let _ = r.read_byte();
+        let rendered = std::format!("{}", r.display());
+        assert!(rendered.contains("consumed:"));
+        assert!(rendered.contains("cursor at offset 0x1"));
+    }
+
+    #[test]
+    fn since_recovers_the_span_between_a_mark_and_the_current_position() {
+        let input = Input::from(b"hello world");
+        let mut r = Reader::new(input);
+        let _ = r.read_bytes(6).unwrap();
+        let start = r.mark();
+        let _ = r.read_bytes(5).unwrap();
+        let span = r.since(start).unwrap();
+        assert_eq!(span, &b"world"[..]);
+    }
+
+    #[test]
+    fn get_input_between_is_none_when_marks_are_out_of_order() {
+        let input = Input::from(b"hello world");
+        let mut r = Reader::new(input);
+        let m1 = r.mark();
+        let _ = r.read_bytes(5).unwrap();
+        let m2 = r.mark();
+        assert_eq!(r.get_input_between(m2, m1), None);
+        assert!(r.get_input_between(m1, m2).is_some());
+    }
+
+    #[test]
+    fn get_input_between_is_none_for_marks_from_a_different_reader() {
+        let a = [1u8, 2, 3, 4, 5];
+        let b = [9u8, 8, 7, 6, 5];
+        let mut reader_a = Reader::new(Input::from(&a));
+        let mut reader_b = Reader::new(Input::from(&b));
+
+        let m1 = reader_a.mark();
+        let _ = reader_a.read_bytes(2).unwrap();
+        let m2 = reader_a.mark();
+        let _ = reader_b.read_byte();
+
+        assert_eq!(reader_b.get_input_between(m1, m2), None);
+        assert_eq!(reader_a.get_input_between(m1, m2), Some(Input::from(&a[..2])));
+    }
+
+    #[test]
+    fn count_counts_occurrences_of_the_needle() {
+        let input = Input::from(b"mississippi");
+        assert_eq!(input.count(b'i'), 4);
+        assert_eq!(input.count(b's'), 4);
+        assert_eq!(input.count(b'z'), 0);
+    }
+
+    #[test]
+    fn is_within_is_true_for_a_sub_slice_of_the_parent() {
+        let bytes = [1u8, 2, 3, 4, 5];
+        let parent = Input::from(&bytes);
+        let (_, child) = parent.split_at(2).unwrap();
+        assert!(child.is_within(&parent));
+        assert!(parent.is_within(&parent));
+    }
+
+    #[test]
+    fn is_within_is_false_for_an_unrelated_buffer() {
+        let a = [1u8, 2, 3];
+        let b = [1u8, 2, 3];
+        let input_a = Input::from(&a);
+        let input_b = Input::from(&b);
+        assert!(!input_a.is_within(&input_b));
+    }
+}